@@ -1,12 +1,80 @@
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use colored::*;
-use std::net::Ipv4Addr;
+use serde::Serialize;
+use std::net::{Ipv4Addr, Ipv6Addr};
 use std::str::FromStr;
 
 #[derive(Parser)]
-#[command(author = "d4nilpzz", version = "1.0.0", about = "ipcalc - simple IPv4/CIDR calculator")]
+#[command(author = "d4nilpzz", version = "1.0.0", about = "ipcalc - simple IPv4/IPv6/CIDR calculator")]
 struct Args {
     input: String,
+
+    /// Netmask, when given as a separate argument instead of after a '/'
+    /// (e.g. `ipcalc 192.168.1.10 255.255.255.0`)
+    mask: Option<String>,
+
+    /// Split the network into equal child subnets at this prefix length
+    #[arg(long, value_name = "PREFIX", conflicts_with = "subnets")]
+    split: Option<u8>,
+
+    /// Split the network into at least this many equal child subnets
+    /// (the count is rounded up to the next power of two)
+    #[arg(long, value_name = "COUNT")]
+    subnets: Option<u32>,
+
+    /// Allocate a comma-separated list of host requirements via VLSM
+    #[arg(long, value_name = "COUNTS")]
+    vlsm: Option<String>,
+
+    /// Output format
+    #[arg(long, value_enum, default_value_t = OutputFormat::Text)]
+    format: OutputFormat,
+
+    /// Check whether this network is contained within the given network
+    #[arg(long = "in", value_name = "NETWORK", conflicts_with = "overlaps")]
+    contains_in: Option<String>,
+
+    /// Check whether this network overlaps with the given network
+    #[arg(long, value_name = "NETWORK")]
+    overlaps: Option<String>,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    Text,
+    Json,
+}
+
+#[derive(Serialize)]
+struct BinaryDump {
+    address: String,
+    netmask: String,
+    wildcard: String,
+    network: String,
+    host_min: String,
+    host_max: String,
+    broadcast: String,
+}
+
+#[derive(Serialize)]
+struct Ipv4Result {
+    address: Ipv4Addr,
+    netmask: Ipv4Addr,
+    prefix: u8,
+    wildcard: Ipv4Addr,
+    network: Ipv4Addr,
+    host_min: Ipv4Addr,
+    host_max: Ipv4Addr,
+    broadcast: Ipv4Addr,
+    hosts_net: i64,
+    class: String,
+    is_private: bool,
+    binary: BinaryDump,
+}
+
+enum IpAddress {
+    V4(Ipv4Addr),
+    V6(Ipv6Addr),
 }
 
 fn ipv4_to_u32(a: Ipv4Addr) -> u32 {
@@ -26,6 +94,23 @@ fn wildcard_from_mask(mask: u32) -> u32 {
     !mask
 }
 
+fn ipv6_to_u128(a: Ipv6Addr) -> u128 {
+    u128::from_be_bytes(a.octets())
+}
+fn u128_to_ipv6(x: u128) -> Ipv6Addr {
+    Ipv6Addr::from(x.to_be_bytes())
+}
+fn mask_from_prefix_v6(prefix: u8) -> u128 {
+    if prefix == 0 {
+        0
+    } else {
+        (!0u128) << (128 - prefix)
+    }
+}
+fn wildcard_from_mask_v6(mask: u128) -> u128 {
+    !mask
+}
+
 fn to_binary(octets: [u8; 4]) -> String {
     format!("{:08b} {:08b} {:08b} {:08b}", octets[0], octets[1], octets[2], octets[3])
 }
@@ -48,9 +133,73 @@ fn binary_octets_with_split(x: u32, prefix: u8) -> String {
     strs.join(" ")
 }
 
+fn to_binary_v6(x: u128) -> String {
+    let bytes = x.to_be_bytes();
+    let mut hextets: Vec<String> = vec![];
+    for chunk in bytes.chunks(2) {
+        let hextet = u16::from_be_bytes([chunk[0], chunk[1]]);
+        hextets.push(format!("{:016b}", hextet));
+    }
+    hextets.join(" ")
+}
+
+fn binary_hextets_with_split(x: u128, prefix: u8) -> String {
+    let bytes = x.to_be_bytes();
+    let mut strs: Vec<String> = vec![];
+    let split_hextet = (prefix / 16) as usize;
+    let split_offset = (prefix % 16) as usize;
+    for (i, chunk) in bytes.chunks(2).enumerate() {
+        let hextet = u16::from_be_bytes([chunk[0], chunk[1]]);
+        let s = format!("{:016b}", hextet);
+        if i == split_hextet && split_offset != 0 {
+            let (a, b) = s.split_at(split_offset);
+            strs.push(a.to_string());
+            strs.push(b.to_string());
+        } else {
+            strs.push(s);
+        }
+    }
+    strs.join(" ")
+}
+
+struct SpecialRange {
+    network: u32,
+    prefix: u8,
+    label: &'static str,
+}
+
+// IANA IPv4 Special-Purpose Address Registry (RFC 6890 and successors),
+// checked most-specific match first. RFC 1918 private space and loopback
+// keep their legacy Class A/B/C-style handling below so that `is_private`
+// and the text output stay exactly as they were before this table existed;
+// this only covers the special-purpose ranges that had no label at all.
+const SPECIAL_RANGES: &[SpecialRange] = &[
+    SpecialRange { network: 0x0000_0000, prefix: 8, label: "\"This host\" (RFC 791)" },
+    SpecialRange { network: 0x6440_0000, prefix: 10, label: "Shared Address Space / CGNAT (RFC 6598)" },
+    SpecialRange { network: 0xA9FE_0000, prefix: 16, label: "Link-Local / APIPA (RFC 3927)" },
+    SpecialRange { network: 0xC000_0200, prefix: 24, label: "Documentation TEST-NET-1 (RFC 5737)" },
+    SpecialRange { network: 0xC612_0000, prefix: 15, label: "Benchmarking (RFC 2544)" },
+    SpecialRange { network: 0xC633_6400, prefix: 24, label: "Documentation TEST-NET-2 (RFC 5737)" },
+    SpecialRange { network: 0xCB00_7100, prefix: 24, label: "Documentation TEST-NET-3 (RFC 5737)" },
+    SpecialRange { network: 0xFFFF_FFFF, prefix: 32, label: "Limited Broadcast (RFC 919)" },
+];
+
 fn detect_class_and_priv(addr: Ipv4Addr) -> (String, bool) {
-    let o1 = addr.octets()[0];
-    let class = match o1 {
+    let ip_u = ipv4_to_u32(addr);
+
+    let mut best: Option<&SpecialRange> = None;
+    for range in SPECIAL_RANGES {
+        if ip_u & mask_from_prefix(range.prefix) == range.network {
+            if best.map_or(true, |b| range.prefix > b.prefix) {
+                best = Some(range);
+            }
+        }
+    }
+    if let Some(range) = best {
+        return (range.label.to_string(), false);
+    }
+
+    let class = match addr.octets()[0] {
         0 => "Address with 0.x (reserved)".to_string(),
         1..=126 => "Class A".to_string(),
         127 => "Loopback".to_string(),
@@ -68,28 +217,295 @@ fn detect_class_and_priv(addr: Ipv4Addr) -> (String, bool) {
     (class, is_private)
 }
 
-fn main() {
-    let args = Args::parse();
-    let parts: Vec<&str> = args.input.split('/').collect();
+fn detect_scope_v6(addr: Ipv6Addr) -> (String, bool) {
+    let ip_u = ipv6_to_u128(addr);
+    let linklocal_net = ipv6_to_u128(Ipv6Addr::from_str("fe80::").unwrap());
+    let ula_net = ipv6_to_u128(Ipv6Addr::from_str("fc00::").unwrap());
+    let multicast_net = ipv6_to_u128(Ipv6Addr::from_str("ff00::").unwrap());
+
+    let scope = if addr.is_loopback() {
+        "Loopback".to_string()
+    } else if addr.is_unspecified() {
+        "Unspecified".to_string()
+    } else if ip_u & mask_from_prefix_v6(10) == linklocal_net {
+        "Link-Local".to_string()
+    } else if ip_u & mask_from_prefix_v6(7) == ula_net {
+        "Unique Local Address (ULA)".to_string()
+    } else if ip_u & mask_from_prefix_v6(8) == multicast_net {
+        "Multicast".to_string()
+    } else {
+        "Global Unicast".to_string()
+    };
+    let is_private = scope == "Unique Local Address (ULA)" || scope == "Link-Local";
+    (scope, is_private)
+}
+
+fn mask_to_prefix(mask: u32) -> Result<u8, String> {
+    let wildcard = wildcard_from_mask(mask);
+    if wildcard & wildcard.wrapping_add(1) != 0 {
+        return Err(format!(
+            "Netmask {} is not a valid contiguous netmask",
+            u32_to_ipv4(mask)
+        ));
+    }
+    Ok(mask.count_ones() as u8)
+}
+
+fn parse_v4_prefix(spec: &str) -> Result<u8, String> {
+    if let Ok(p) = spec.parse::<u8>() {
+        return if p <= 32 {
+            Ok(p)
+        } else {
+            Err("Invalid prefix (0-32)".to_string())
+        };
+    }
+    if let Ok(mask_ip) = Ipv4Addr::from_str(spec) {
+        return mask_to_prefix(ipv4_to_u32(mask_ip));
+    }
+    if let Some(hex) = spec.strip_prefix("0x").or_else(|| spec.strip_prefix("0X")) {
+        let mask_u = u32::from_str_radix(hex, 16).map_err(|_| "Invalid hex netmask".to_string())?;
+        return mask_to_prefix(mask_u);
+    }
+    Err("Invalid prefix (0-32)".to_string())
+}
+
+fn parse_input(input: &str) -> Result<(IpAddress, u8), String> {
+    let parts: Vec<&str> = input.split('/').collect();
     if parts.len() != 2 {
-        eprintln!("{}", "Invalid Format. Use: ipcalc x.x.x.x/(0-32)".red());
-        std::process::exit(1);
+        return Err("Invalid Format. Use: ipcalc x.x.x.x/(0-32) or ipcalc xxxx::/(0-128)".to_string());
     }
-    let ip = match Ipv4Addr::from_str(parts[0]) {
-        Ok(a) => a,
-        Err(_) => {
-            eprintln!("{}", "Invalid IP".red());
-            std::process::exit(1);
+
+    if let Ok(ip) = Ipv4Addr::from_str(parts[0]) {
+        let prefix = parse_v4_prefix(parts[1])?;
+        return Ok((IpAddress::V4(ip), prefix));
+    }
+
+    if let Ok(ip) = Ipv6Addr::from_str(parts[0]) {
+        let prefix: u8 = match parts[1].parse() {
+            Ok(p) if p <= 128 => p,
+            _ => return Err("Invalid prefix (0-128)".to_string()),
+        };
+        return Ok((IpAddress::V6(ip), prefix));
+    }
+
+    Err("Invalid IP".to_string())
+}
+
+fn subnet_count_to_prefix(prefix: u8, count: u32) -> Result<u8, String> {
+    if count == 0 {
+        return Err("Subnet count must be at least 1".to_string());
+    }
+    let mut bits = 0u8;
+    while (1u128 << bits) < count as u128 {
+        bits += 1;
+    }
+    let new_prefix = prefix + bits;
+    if new_prefix > 32 {
+        return Err("Requested subnet count does not fit in this network".to_string());
+    }
+    Ok(new_prefix)
+}
+
+fn print_splits(network: u32, prefix: u8, broadcast: u32, new_prefix: u8) -> Result<(), String> {
+    if new_prefix <= prefix {
+        return Err(format!(
+            "New prefix /{} must be longer than the network's own prefix /{}",
+            new_prefix, prefix
+        ));
+    }
+    if new_prefix > 32 {
+        return Err("New prefix must be at most /32".to_string());
+    }
+
+    let data_blue = |s: &str| s.blue().bold();
+    let label = |s: &str| s.bright_black();
+
+    let block: u128 = 1u128 << (new_prefix - prefix);
+    let step: u64 = 1u64 << (32 - new_prefix);
+
+    println!();
+    println!(
+        "{}",
+        label(&format!("Splitting {}/{} into {} subnets of /{}", u32_to_ipv4(network), prefix, block, new_prefix))
+    );
+    println!();
+
+    let sub_mask = mask_from_prefix(new_prefix);
+    let sub_wildcard = wildcard_from_mask(sub_mask);
+
+    let mut addr: u64 = network as u64;
+    while addr <= broadcast as u64 {
+        let sub_network = addr as u32 & sub_mask;
+        let sub_broadcast = sub_network | sub_wildcard;
+        let sub_host_min = if new_prefix >= 31 { sub_network } else { sub_network + 1 };
+        let sub_host_max = if new_prefix >= 31 { sub_broadcast } else { sub_broadcast - 1 };
+
+        println!(
+            "{}\t{}",
+            data_blue(&format!("{}/{}", u32_to_ipv4(sub_network), new_prefix)).to_string(),
+            label(&format!(
+                "HostMin: {}  HostMax: {}  Broadcast: {}",
+                u32_to_ipv4(sub_host_min),
+                u32_to_ipv4(sub_host_max),
+                u32_to_ipv4(sub_broadcast)
+            ))
+        );
+
+        addr += step;
+    }
+    println!();
+    Ok(())
+}
+
+fn prefix_for_hosts(required: u32) -> Result<u8, String> {
+    for p in (0..=32u8).rev() {
+        let usable: i64 = match p {
+            31 => 2,
+            32 => 1,
+            _ => {
+                let host_bits = 32 - p;
+                (1i64 << host_bits) - 2
+            }
+        };
+        if usable >= required as i64 {
+            return Ok(p);
         }
-    };
-    let prefix: u8 = match parts[1].parse() {
-        Ok(p) if p <= 32 => p,
-        _ => {
-            eprintln!("{}", "Invalid prefix (0-32)".red());
-            std::process::exit(1);
+    }
+    Err(format!("No prefix can host {} addresses", required))
+}
+
+fn print_vlsm(network: u32, prefix: u8, requirements: &str) -> Result<(), String> {
+    let mut required: Vec<u32> = vec![];
+    for part in requirements.split(',') {
+        let n: u32 = part
+            .trim()
+            .parse()
+            .map_err(|_| format!("Invalid host requirement: '{}'", part.trim()))?;
+        required.push(n);
+    }
+    required.sort_unstable_by(|a, b| b.cmp(a));
+
+    let parent_mask = mask_from_prefix(prefix);
+    let parent_wildcard = wildcard_from_mask(parent_mask);
+    let parent_size = parent_wildcard as u64 + 1;
+
+    let data_blue = |s: &str| s.blue().bold();
+    let label = |s: &str| s.bright_black();
+
+    println!();
+    println!(
+        "{}",
+        label(&format!("VLSM allocation for {}/{}", u32_to_ipv4(network), prefix))
+    );
+    println!();
+
+    let mut cursor: u64 = network as u64;
+    for req in required {
+        let child_prefix = prefix_for_hosts(req)?;
+        let size: u64 = 1u64 << (32 - child_prefix);
+
+        let aligned_cursor = (cursor + size - 1) & !(size - 1);
+        if aligned_cursor + size - 1 > network as u64 + parent_size - 1 {
+            return Err(format!(
+                "Allocation for {} hosts does not fit within {}/{}",
+                req,
+                u32_to_ipv4(network),
+                prefix
+            ));
         }
-    };
 
+        let sub_network = aligned_cursor as u32;
+        let sub_mask = mask_from_prefix(child_prefix);
+        let sub_wildcard = wildcard_from_mask(sub_mask);
+        let sub_broadcast = sub_network | sub_wildcard;
+        let sub_host_min = if child_prefix >= 31 { sub_network } else { sub_network + 1 };
+        let sub_host_max = if child_prefix >= 31 { sub_broadcast } else { sub_broadcast - 1 };
+        let usable: i64 = if child_prefix >= 31 { size as i64 } else { size as i64 - 2 };
+        let wasted = usable - req as i64;
+
+        println!(
+            "{}\t{}",
+            data_blue(&format!("{}/{}", u32_to_ipv4(sub_network), child_prefix)).to_string(),
+            label(&format!(
+                "requested: {}  usable: {}  wasted: {}  HostMin: {}  HostMax: {}  Broadcast: {}",
+                req,
+                usable,
+                wasted,
+                u32_to_ipv4(sub_host_min),
+                u32_to_ipv4(sub_host_max),
+                u32_to_ipv4(sub_broadcast)
+            ))
+        );
+
+        cursor = aligned_cursor + size;
+    }
+
+    println!();
+    Ok(())
+}
+
+fn parse_v4_network(spec: &str) -> Result<(u32, u8), String> {
+    match parse_input(spec)? {
+        (IpAddress::V4(ip), prefix) => Ok((ipv4_to_u32(ip) & mask_from_prefix(prefix), prefix)),
+        (IpAddress::V6(_), _) => Err("Only IPv4 networks are supported for --in/--overlaps".to_string()),
+    }
+}
+
+fn print_containment(a_network: u32, a_prefix: u8, other: &str) -> Result<(), String> {
+    let (b_network, b_prefix) = parse_v4_network(other)?;
+
+    let label = |s: &str| s.bright_black();
+    let data_blue = |s: &str| s.blue().bold();
+
+    let contained = b_prefix <= a_prefix && (a_network & mask_from_prefix(b_prefix)) == b_network;
+
+    println!();
+    if contained {
+        let blocks: u128 = 1u128 << (a_prefix - b_prefix);
+        println!(
+            "{} {}",
+            data_blue(&format!("{}/{}", u32_to_ipv4(a_network), a_prefix)).to_string(),
+            label(&format!(
+                "is contained within {}/{} ({} /{} block(s) fit in it)",
+                u32_to_ipv4(b_network),
+                b_prefix,
+                blocks,
+                a_prefix
+            ))
+        );
+    } else {
+        println!(
+            "{} {}",
+            data_blue(&format!("{}/{}", u32_to_ipv4(a_network), a_prefix)).to_string(),
+            label(&format!("is NOT contained within {}/{}", u32_to_ipv4(b_network), b_prefix))
+        );
+    }
+    println!();
+    Ok(())
+}
+
+fn print_overlap(a_network: u32, a_prefix: u8, other: &str) -> Result<(), String> {
+    let (b_network, b_prefix) = parse_v4_network(other)?;
+
+    let label = |s: &str| s.bright_black();
+    let data_blue = |s: &str| s.blue().bold();
+
+    let broader_prefix = a_prefix.min(b_prefix);
+    let broader_mask = mask_from_prefix(broader_prefix);
+    let overlaps = (a_network & broader_mask) == (b_network & broader_mask);
+
+    println!();
+    let verb = if overlaps { "overlaps with" } else { "does NOT overlap with" };
+    println!(
+        "{} {}",
+        data_blue(&format!("{}/{}", u32_to_ipv4(a_network), a_prefix)).to_string(),
+        label(&format!("{} {}/{}", verb, u32_to_ipv4(b_network), b_prefix))
+    );
+    println!();
+    Ok(())
+}
+
+fn run_v4(ip: Ipv4Addr, prefix: u8, split: Option<u8>, subnets: Option<u32>, vlsm: Option<&str>, format: OutputFormat, contains_in: Option<&str>, overlaps: Option<&str>) {
     let ip_u = ipv4_to_u32(ip);
     let mask = mask_from_prefix(prefix);
     let wildcard = wildcard_from_mask(mask);
@@ -113,10 +529,10 @@ fn main() {
     let host_max = if prefix >= 31 { broadcast } else { broadcast - 1 };
 
     let (class, is_private) = detect_class_and_priv(u32_to_ipv4(ip_u));
-
-    let label = |s: &str| s.bright_black();
-    let data_blue = |s: &str| s.blue().bold();
-    let data_purp = |s: &str| s.magenta().bold();
+    let mut class_info = class;
+    if is_private {
+        class_info.push_str(", Private Internet");
+    }
 
     let ip_bin = binary_octets_with_split(ip_u, prefix);
     let mask_bin = binary_octets_with_split(mask, prefix);
@@ -126,6 +542,43 @@ fn main() {
     let hostmax_bin = to_binary(host_max.to_be_bytes());
     let broadcast_bin = to_binary(broadcast.to_be_bytes());
 
+    if format == OutputFormat::Json {
+        let result = Ipv4Result {
+            address: u32_to_ipv4(ip_u),
+            netmask: u32_to_ipv4(mask),
+            prefix,
+            wildcard: u32_to_ipv4(wildcard),
+            network: u32_to_ipv4(network),
+            host_min: u32_to_ipv4(host_min),
+            host_max: u32_to_ipv4(host_max),
+            broadcast: u32_to_ipv4(broadcast),
+            hosts_net,
+            class: class_info.clone(),
+            is_private,
+            binary: BinaryDump {
+                address: ip_bin,
+                netmask: mask_bin,
+                wildcard: wildcard_bin,
+                network: network_bin,
+                host_min: hostmin_bin,
+                host_max: hostmax_bin,
+                broadcast: broadcast_bin,
+            },
+        };
+        match serde_json::to_string_pretty(&result) {
+            Ok(s) => println!("{}", s),
+            Err(e) => {
+                eprintln!("{}", format!("Failed to serialize result: {}", e).red());
+                std::process::exit(1);
+            }
+        }
+        return;
+    }
+
+    let label = |s: &str| s.bright_black();
+    let data_blue = |s: &str| s.blue().bold();
+    let data_purp = |s: &str| s.magenta().bold();
+
     println!();
     println!(
         "{}\t{}\t\t{}",
@@ -174,15 +627,319 @@ fn main() {
 
 
     println!();
-    let mut class_info = class;
+    println!(
+        "{}\t{}\t{}",
+        label("Hosts/Net:").to_string(),
+        data_blue(&format!("{}", hosts_net)).to_string(),
+        data_purp(&format!("{}", class_info)).to_string()
+    );
+    println!();
+
+    if split.is_some() || subnets.is_some() {
+        let new_prefix = match split {
+            Some(p) => p,
+            None => match subnet_count_to_prefix(prefix, subnets.unwrap()) {
+                Ok(p) => p,
+                Err(e) => {
+                    eprintln!("{}", e.red());
+                    std::process::exit(1);
+                }
+            },
+        };
+        if let Err(e) = print_splits(network, prefix, broadcast, new_prefix) {
+            eprintln!("{}", e.red());
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(requirements) = vlsm {
+        if let Err(e) = print_vlsm(network, prefix, requirements) {
+            eprintln!("{}", e.red());
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(other) = contains_in {
+        if let Err(e) = print_containment(network, prefix, other) {
+            eprintln!("{}", e.red());
+            std::process::exit(1);
+        }
+    }
+
+    if let Some(other) = overlaps {
+        if let Err(e) = print_overlap(network, prefix, other) {
+            eprintln!("{}", e.red());
+            std::process::exit(1);
+        }
+    }
+}
+
+fn run_v6(ip: Ipv6Addr, prefix: u8) {
+    let ip_u = ipv6_to_u128(ip);
+    let mask = mask_from_prefix_v6(prefix);
+    let wildcard = wildcard_from_mask_v6(mask);
+    let network = ip_u & mask;
+    let host_max = network | wildcard;
+
+    let host_bits = 128 - prefix;
+    // 2^128 overflows u128::MAX (2^128 - 1), so the /0 case is spelled out as a literal.
+    let hosts_net: String = if host_bits >= 128 {
+        "340282366920938463463374607431768211456".to_string()
+    } else {
+        (1u128 << host_bits).to_string()
+    };
+
+    let (scope, is_private) = detect_scope_v6(u128_to_ipv6(ip_u));
+
+    let label = |s: &str| s.bright_black();
+    let data_blue = |s: &str| s.blue().bold();
+    let data_purp = |s: &str| s.magenta().bold();
+
+    let ip_bin = binary_hextets_with_split(ip_u, prefix);
+    let mask_bin = binary_hextets_with_split(mask, prefix);
+    let wildcard_bin = to_binary_v6(wildcard);
+    let network_bin = binary_hextets_with_split(network, prefix);
+    let hostmin_bin = binary_hextets_with_split(network, prefix);
+    let hostmax_bin = to_binary_v6(host_max);
+
+    println!();
+    println!(
+        "{}\t{}\t\t{}",
+        label("Address:").to_string(),
+        data_blue(&format!("{}", u128_to_ipv6(ip_u))).to_string(),
+        data_purp(&ip_bin).to_string()
+    );
+    println!(
+        "{}\t{}\t= {}\t{}",
+        label("Netmask:").to_string(),
+        data_blue(&format!("{}", u128_to_ipv6(mask))).to_string(),
+        data_blue(&format!("{}", prefix)).to_string(),
+        data_purp(&mask_bin).to_string()
+    );
+    println!(
+        "{}\t{}\t\t{}",
+        label("Wildcard:").to_string(),
+        data_blue(&format!("{}", u128_to_ipv6(wildcard))).to_string(),
+        data_purp(&wildcard_bin).to_string()
+    );
+    println!();
+    println!(
+        "{}\t{}\t\t{}",
+        label("Network:").to_string(),
+        data_blue(&format!("{}/{}", u128_to_ipv6(network), prefix)).to_string(),
+        data_purp(&network_bin).to_string()
+    );
+    println!(
+        "{}\t{}\t\t{}",
+        label("HostMin:").to_string(),
+        data_blue(&format!("{}", u128_to_ipv6(network))).to_string(),
+        data_purp(&hostmin_bin).to_string()
+    );
+    println!(
+        "{}\t{}\t\t{}",
+        label("HostMax:").to_string(),
+        data_blue(&format!("{}", u128_to_ipv6(host_max))).to_string(),
+        data_purp(&hostmax_bin).to_string()
+    );
+
+    println!();
+    let mut scope_info = scope;
     if is_private {
-        class_info.push_str(", Private Internet");
+        scope_info.push_str(", Private Internet");
     }
     println!(
         "{}\t{}\t{}",
         label("Hosts/Net:").to_string(),
         data_blue(&format!("{}", hosts_net)).to_string(),
-        data_purp(&format!("{}", class_info)).to_string()
+        data_purp(&format!("{}", scope_info)).to_string()
     );
     println!();
-}
\ No newline at end of file
+}
+
+fn main() {
+    let args = Args::parse();
+
+    let combined_input = match &args.mask {
+        Some(mask) => format!("{}/{}", args.input, mask),
+        None => args.input.clone(),
+    };
+
+    let (addr, prefix) = match parse_input(&combined_input) {
+        Ok(v) => v,
+        Err(e) => {
+            eprintln!("{}", e.red());
+            std::process::exit(1);
+        }
+    };
+
+    match addr {
+        IpAddress::V4(ip) => {
+            let other_mode_requested = args.split.is_some()
+                || args.subnets.is_some()
+                || args.vlsm.is_some()
+                || args.contains_in.is_some()
+                || args.overlaps.is_some();
+            if args.format == OutputFormat::Json && other_mode_requested {
+                eprintln!(
+                    "{}",
+                    "--format json cannot be combined with --split/--subnets/--vlsm/--in/--overlaps".red()
+                );
+                std::process::exit(1);
+            }
+            run_v4(
+                ip,
+                prefix,
+                args.split,
+                args.subnets,
+                args.vlsm.as_deref(),
+                args.format,
+                args.contains_in.as_deref(),
+                args.overlaps.as_deref(),
+            )
+        }
+        IpAddress::V6(ip) => {
+            if args.split.is_some() || args.subnets.is_some() || args.vlsm.is_some() {
+                eprintln!("{}", "--split/--subnets/--vlsm is not yet supported for IPv6".red());
+                std::process::exit(1);
+            }
+            if args.format == OutputFormat::Json {
+                eprintln!("{}", "--format json is not yet supported for IPv6".red());
+                std::process::exit(1);
+            }
+            if args.contains_in.is_some() || args.overlaps.is_some() {
+                eprintln!("{}", "--in/--overlaps is not yet supported for IPv6".red());
+                std::process::exit(1);
+            }
+            run_v6(ip, prefix)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn mask_from_prefix_covers_edges() {
+        assert_eq!(mask_from_prefix(0), 0);
+        assert_eq!(mask_from_prefix(24), 0xFFFF_FF00);
+        assert_eq!(mask_from_prefix(32), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn wildcard_from_mask_is_mask_complement() {
+        assert_eq!(wildcard_from_mask(0xFFFF_FF00), 0x0000_00FF);
+        assert_eq!(wildcard_from_mask(0), 0xFFFF_FFFF);
+    }
+
+    #[test]
+    fn mask_from_prefix_v6_covers_edges() {
+        assert_eq!(mask_from_prefix_v6(0), 0);
+        assert_eq!(mask_from_prefix_v6(128), u128::MAX);
+        assert_eq!(mask_from_prefix_v6(48), (!0u128) << 80);
+    }
+
+    #[test]
+    fn mask_to_prefix_accepts_contiguous_masks() {
+        assert_eq!(mask_to_prefix(0xFFFF_FF00), Ok(24));
+        assert_eq!(mask_to_prefix(0xFFFF_FFFF), Ok(32));
+        assert_eq!(mask_to_prefix(0), Ok(0));
+    }
+
+    #[test]
+    fn mask_to_prefix_rejects_non_contiguous_masks() {
+        // 255.0.255.0 has a zero bit followed by a one bit.
+        assert!(mask_to_prefix(0xFF00_FF00).is_err());
+    }
+
+    #[test]
+    fn parse_v4_prefix_accepts_plain_prefix() {
+        assert_eq!(parse_v4_prefix("24"), Ok(24));
+        assert!(parse_v4_prefix("33").is_err());
+    }
+
+    #[test]
+    fn parse_v4_prefix_accepts_dotted_mask() {
+        assert_eq!(parse_v4_prefix("255.255.255.0"), Ok(24));
+        assert!(parse_v4_prefix("255.0.255.0").is_err());
+    }
+
+    #[test]
+    fn parse_v4_prefix_accepts_hex_mask() {
+        assert_eq!(parse_v4_prefix("0xffffff00"), Ok(24));
+        assert_eq!(parse_v4_prefix("0XFFFFFF00"), Ok(24));
+    }
+
+    #[test]
+    fn subnet_count_to_prefix_picks_smallest_fitting_prefix() {
+        assert_eq!(subnet_count_to_prefix(24, 1), Ok(24));
+        assert_eq!(subnet_count_to_prefix(24, 2), Ok(25));
+        assert_eq!(subnet_count_to_prefix(24, 4), Ok(26));
+        assert_eq!(subnet_count_to_prefix(24, 5), Ok(27));
+        assert!(subnet_count_to_prefix(24, 0).is_err());
+        assert!(subnet_count_to_prefix(31, 4).is_err());
+    }
+
+    #[test]
+    fn prefix_for_hosts_special_cases_slash_31_and_32() {
+        assert_eq!(prefix_for_hosts(1), Ok(32));
+        assert_eq!(prefix_for_hosts(2), Ok(31));
+        assert_eq!(prefix_for_hosts(3), Ok(29));
+        assert_eq!(prefix_for_hosts(6), Ok(29));
+        assert_eq!(prefix_for_hosts(254), Ok(24));
+    }
+
+    #[test]
+    fn prefix_for_hosts_rejects_impossible_requirements() {
+        assert!(prefix_for_hosts(u32::MAX).is_err());
+    }
+
+    #[test]
+    fn ipv6_zero_prefix_host_count_is_exactly_two_pow_128() {
+        let host_bits: u8 = 128;
+        let hosts_net: String = if host_bits >= 128 {
+            "340282366920938463463374607431768211456".to_string()
+        } else {
+            (1u128 << host_bits).to_string()
+        };
+        // 2^128 == u128::MAX + 1, and u128::MAX ends in the digit 5, so 2^128 is
+        // u128::MAX with its last digit raised by one; confirm the literal against that.
+        let expected = format!("{}{}", u128::MAX / 10, u128::MAX % 10 + 1);
+        assert_eq!(hosts_net, expected);
+    }
+
+    #[test]
+    fn ipv6_small_prefix_host_count_fits_in_u128() {
+        let host_bits: u8 = 64;
+        let hosts_net: String = (1u128 << host_bits).to_string();
+        assert_eq!(hosts_net, (1u128 << 64).to_string());
+    }
+
+    #[test]
+    fn detect_class_and_priv_labels_rfc1918_as_private() {
+        let (class, is_private) = detect_class_and_priv(Ipv4Addr::new(10, 0, 0, 1));
+        assert!(is_private);
+        assert_eq!(class, "Class A");
+
+        let (_, is_private) = detect_class_and_priv(Ipv4Addr::new(192, 168, 1, 1));
+        assert!(is_private);
+    }
+
+    #[test]
+    fn detect_class_and_priv_does_not_mark_loopback_or_cgnat_private() {
+        let (class, is_private) = detect_class_and_priv(Ipv4Addr::new(127, 0, 0, 1));
+        assert!(!is_private);
+        assert_eq!(class, "Loopback");
+
+        let (_, is_private) = detect_class_and_priv(Ipv4Addr::new(100, 64, 0, 1));
+        assert!(!is_private);
+    }
+
+    #[test]
+    fn parse_input_detects_family_and_validates_prefix() {
+        assert!(matches!(parse_input("10.0.0.0/24"), Ok((IpAddress::V4(_), 24))));
+        assert!(matches!(parse_input("2001:db8::/48"), Ok((IpAddress::V6(_), 48))));
+        assert!(parse_input("10.0.0.0/33").is_err());
+        assert!(parse_input("2001:db8::/129").is_err());
+    }
+}